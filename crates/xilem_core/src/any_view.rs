@@ -88,8 +88,11 @@ macro_rules! generate_anyview_trait {
                 if let Some(state) = state.downcast_mut() {
                     self.message(id_path, state, message, app_state)
                 } else {
-                    // Possibly softer failure?
-                    panic!("downcast error in dyn_event");
+                    // A broken view/state invariant; only abort when explicitly requested.
+                    if cfg!(feature = "panic-on-state-mismatch") {
+                        panic!("downcast of state failed in dyn_message");
+                    }
+                    $crate::MessageResult::Stale(message)
                 }
             }
         }
@@ -54,9 +54,36 @@ macro_rules! impl_view_tuple {
     }
 }
 
+/// Builds a view sequence out of any number of view sequences.
+///
+/// Tuples are view sequences for up to 16 elements. This macro produces a flat
+/// tuple for short sequences and nests the tail into an inner tuple otherwise,
+/// so long sequences of mixed views don't hit that limit.
+#[macro_export]
+macro_rules! fragment {
+    (
+        $s0:expr, $s1:expr, $s2:expr, $s3:expr, $s4:expr, $s5:expr, $s6:expr, $s7:expr,
+        $s8:expr, $s9:expr, $s10:expr, $s11:expr, $s12:expr, $s13:expr, $s14:expr,
+        $r0:expr, $( $rest:expr ),+ $(,)?
+    ) => {
+        (
+            $s0, $s1, $s2, $s3, $s4, $s5, $s6, $s7,
+            $s8, $s9, $s10, $s11, $s12, $s13, $s14,
+            $crate::fragment!($r0, $( $rest ),+),
+        )
+    };
+    ( $( $seq:expr ),+ $(,)? ) => {
+        ( $( $seq, )+ )
+    };
+}
+
 #[macro_export]
 macro_rules! generate_viewsequence_trait {
     ($viewseq:ident, $view:ident, $viewmarker: ident, $bound:ident, $cx:ty, $changeflags:ty, $pod:ty; $( $ss:tt )* ) => {
+        /// A sequence of views, building a dynamic amount of widgets.
+        ///
+        /// This is implemented for single views, `Option`, `Vec` and tuples of up to
+        /// 16 view sequences. Larger sequences can be expressed by nesting tuples.
         pub trait $viewseq<T, A = ()> $( $ss )* {
             /// Associated states for the views.
             type State $( $ss )*;
@@ -312,5 +339,21 @@ macro_rules! generate_viewsequence_trait {
             V0, V1, V2, V3, V4, V5, V6, V7, V8; 0, 1, 2, 3, 4, 5, 6, 7, 8);
         $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
             V0, V1, V2, V3, V4, V5, V6, V7, V8, V9; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+        $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
+            V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+        $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
+            V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+        $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
+            V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12;
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+        $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
+            V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13;
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+        $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
+            V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14;
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14);
+        $crate::impl_view_tuple!($viewseq, $pod, $cx, $changeflags,
+            V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15;
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
     };
 }
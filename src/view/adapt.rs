@@ -14,17 +14,27 @@
 
 use std::{any::Any, marker::PhantomData};
 
-use crate::{event::EventResult, id::Id};
+use crate::view::ViewMarker;
+use crate::{view::Id, widget::ChangeFlags, MessageResult};
 
 use super::{Cx, View};
 
-pub struct Adapt<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> EventResult<A>, C: View<U, B>> {
+/// A view that adapts a child view with a different app state and action type.
+///
+/// This allows a component to be written against its own state `U` and action
+/// `B`, and embedded in an app with state `T` and action `A`. The closure `f` is
+/// given the parent app state and an [`AdaptThunk`]; it should project the
+/// parent state to the child state, call the thunk with it, and map the
+/// resulting [`MessageResult`] back to the parent action type.
+#[allow(clippy::type_complexity)]
+pub struct Adapt<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> MessageResult<A>, C: View<U, B>>
+{
     f: F,
     child: C,
     phantom: PhantomData<fn() -> (T, A, U, B)>,
 }
 
-/// A "thunk" which dispatches an event to an adapt node's child.
+/// A "thunk" which dispatches a message to an adapt node's child.
 ///
 /// The closure passed to Adapt should call this thunk with the child's
 /// app state.
@@ -32,10 +42,34 @@ pub struct AdaptThunk<'a, U, B, C: View<U, B>> {
     child: &'a C,
     state: &'a mut C::State,
     id_path: &'a [Id],
-    event: Box<dyn Any>,
+    message: Box<dyn Any>,
+}
+
+/// Creates a new `Adapt` view.
+pub fn adapt<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> MessageResult<A>, C: View<U, B>>(
+    f: F,
+    child: C,
+) -> Adapt<T, A, U, B, F, C> {
+    Adapt::new(f, child)
+}
+
+/// Creates an `Adapt` view which runs the child on a part of the app state.
+///
+/// This is the common case of [`adapt`] where the closure only projects the app
+/// state and passes the child's result through unchanged. Views only access the
+/// app state while handling messages, so a mutable projection is all that's needed.
+#[allow(clippy::type_complexity)]
+pub fn map_state<T, A, U, C: View<U, A>>(
+    get_mut: impl Fn(&mut T) -> &mut U + Send,
+    child: C,
+) -> Adapt<T, A, U, A, impl Fn(&mut T, AdaptThunk<U, A, C>) -> MessageResult<A> + Send, C> {
+    adapt(
+        move |app_state: &mut T, thunk: AdaptThunk<U, A, C>| thunk.call(get_mut(app_state)),
+        child,
+    )
 }
 
-impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> EventResult<A>, C: View<U, B>>
+impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> MessageResult<A>, C: View<U, B>>
     Adapt<T, A, U, B, F, C>
 {
     pub fn new(f: F, child: C) -> Self {
@@ -48,13 +82,18 @@ impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> EventResult<A>, C: View<U
 }
 
 impl<'a, U, B, C: View<U, B>> AdaptThunk<'a, U, B, C> {
-    pub fn call(self, app_state: &mut U) -> EventResult<B> {
+    pub fn call(self, app_state: &mut U) -> MessageResult<B> {
         self.child
-            .event(self.id_path, self.state, self.event, app_state)
+            .message(self.id_path, self.state, self.message, app_state)
     }
 }
 
-impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> EventResult<A> + Send, C: View<U, B>>
+impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> MessageResult<A>, C: View<U, B>> ViewMarker
+    for Adapt<T, A, U, B, F, C>
+{
+}
+
+impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> MessageResult<A> + Send, C: View<U, B>>
     View<T, A> for Adapt<T, A, U, B, F, C>
 {
     type State = C::State;
@@ -72,23 +111,89 @@ impl<T, A, U, B, F: Fn(&mut T, AdaptThunk<U, B, C>) -> EventResult<A> + Send, C:
         id: &mut Id,
         state: &mut Self::State,
         element: &mut Self::Element,
-    ) -> bool {
+    ) -> ChangeFlags {
         self.child.rebuild(cx, &prev.child, id, state, element)
     }
 
-    fn event(
+    fn message(
         &self,
         id_path: &[Id],
         state: &mut Self::State,
-        event: Box<dyn Any>,
+        message: Box<dyn Any>,
         app_state: &mut T,
-    ) -> EventResult<A> {
+    ) -> MessageResult<A> {
         let thunk = AdaptThunk {
             child: &self.child,
             state,
             id_path,
-            event,
+            message,
         };
         (self.f)(app_state, thunk)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::sync_channel;
+
+    use super::*;
+    use crate::view::button;
+    use test_log::test;
+
+    struct AppState {
+        count: u32,
+    }
+
+    enum AppAction {
+        Counted(u32),
+    }
+
+    fn increment(count: &mut u32) -> u32 {
+        *count += 1;
+        *count
+    }
+
+    #[test]
+    fn adapt_projects_state_and_maps_action() {
+        let (tx, _rx) = sync_channel(1);
+        let mut cx = Cx::new(&tx);
+        let view = adapt(
+            |app_state: &mut AppState, thunk: AdaptThunk<u32, u32, _>| {
+                thunk.call(&mut app_state.count).map(AppAction::Counted)
+            },
+            button("increment", increment),
+        );
+        let (_, mut state, _) = View::<AppState, AppAction>::build(&view, &mut cx);
+
+        let mut app_state = AppState { count: 0 };
+        let result = View::<AppState, AppAction>::message(
+            &view,
+            &[],
+            &mut state,
+            Box::new(()),
+            &mut app_state,
+        );
+        assert_eq!(app_state.count, 1);
+        assert!(matches!(
+            result,
+            MessageResult::Action(AppAction::Counted(1))
+        ));
+    }
+
+    #[test]
+    fn map_state_projects_state() {
+        let (tx, _rx) = sync_channel(1);
+        let mut cx = Cx::new(&tx);
+        let view = map_state(
+            |app_state: &mut AppState| &mut app_state.count,
+            button("increment", increment),
+        );
+        let (_, mut state, _) = View::<AppState, u32>::build(&view, &mut cx);
+
+        let mut app_state = AppState { count: 41 };
+        let result =
+            View::<AppState, u32>::message(&view, &[], &mut state, Box::new(()), &mut app_state);
+        assert_eq!(app_state.count, 42);
+        assert!(matches!(result, MessageResult::Action(42)));
+    }
+}
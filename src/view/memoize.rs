@@ -14,10 +14,17 @@
 
 use std::any::Any;
 
-use crate::{event::EventResult, id::Id};
+use crate::view::ViewMarker;
+use crate::{view::Id, widget::ChangeFlags, MessageResult};
 
 use super::{Cx, View};
 
+/// A view which only rebuilds its child when `data` changes.
+///
+/// The child view is created by `child_cb`, which is only called on build, when
+/// `data` differs from its previous value, and on the first rebuild after the
+/// child returned [`MessageResult::RequestRebuild`] from a message. Otherwise the
+/// retained child view is reused, skipping the rebuild of the whole subtree.
 pub struct Memoize<D, F> {
     data: D,
     child_cb: F,
@@ -29,13 +36,20 @@ pub struct MemoizeState<T, A, V: View<T, A>> {
     dirty: bool,
 }
 
+/// Creates a new `Memoize` view.
+pub fn memoize<D, V, F: Fn(&D) -> V>(data: D, child_cb: F) -> Memoize<D, F> {
+    Memoize::new(data, child_cb)
+}
+
 impl<D, V, F: Fn(&D) -> V> Memoize<D, F> {
     pub fn new(data: D, child_cb: F) -> Self {
         Memoize { data, child_cb }
     }
 }
 
-impl<T, A, D: PartialEq + Clone + Send + 'static, V: View<T, A>, F: Fn(&D) -> V + Send> View<T, A>
+impl<D, F> ViewMarker for Memoize<D, F> {}
+
+impl<T, A, D: PartialEq + Send + 'static, V: View<T, A>, F: Fn(&D) -> V + Send> View<T, A>
     for Memoize<D, F>
 {
     type State = MemoizeState<T, A, V>;
@@ -60,30 +74,114 @@ impl<T, A, D: PartialEq + Clone + Send + 'static, V: View<T, A>, F: Fn(&D) -> V
         id: &mut Id,
         state: &mut Self::State,
         element: &mut Self::Element,
-    ) -> bool {
+    ) -> ChangeFlags {
         if std::mem::take(&mut state.dirty) || prev.data != self.data {
             let view = (self.child_cb)(&self.data);
             let changed = view.rebuild(cx, &state.view, id, &mut state.view_state, element);
             state.view = view;
             changed
         } else {
-            false
+            ChangeFlags::empty()
         }
     }
 
-    fn event(
+    fn message(
         &self,
         id_path: &[Id],
         state: &mut Self::State,
-        event: Box<dyn Any>,
+        message: Box<dyn Any>,
         app_state: &mut T,
-    ) -> EventResult<A> {
+    ) -> MessageResult<A> {
         let r = state
             .view
-            .event(id_path, &mut state.view_state, event, app_state);
-        if matches!(r, EventResult::RequestRebuild) {
+            .message(id_path, &mut state.view_state, message, app_state);
+        if matches!(r, MessageResult::RequestRebuild) {
             state.dirty = true;
         }
         r
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::sync_channel,
+        Arc,
+    };
+
+    use super::*;
+    use crate::view::{adapt, button, AdaptThunk};
+    use test_log::test;
+
+    #[test]
+    fn child_cb_only_called_when_data_changes() {
+        let (tx, _rx) = sync_channel(1);
+        let mut cx = Cx::new(&tx);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let view = |data: u32| {
+            let calls = calls.clone();
+            memoize(data, move |data: &u32| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                button::<(), ()>(data.to_string(), |_| ())
+            })
+        };
+
+        let prev = view(0);
+        let (mut id, mut state, mut element) = View::<(), ()>::build(&prev, &mut cx);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let next = view(0);
+        let changed =
+            View::<(), ()>::rebuild(&next, &mut cx, &prev, &mut id, &mut state, &mut element);
+        assert!(changed.is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let changed_next = view(1);
+        let changed = View::<(), ()>::rebuild(
+            &changed_next,
+            &mut cx,
+            &next,
+            &mut id,
+            &mut state,
+            &mut element,
+        );
+        assert!(!changed.is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn request_rebuild_forces_child_cb() {
+        let (tx, _rx) = sync_channel(1);
+        let mut cx = Cx::new(&tx);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let view = || {
+            let calls = calls.clone();
+            memoize(0, move |_: &u32| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                adapt(
+                    |_: &mut (), thunk: AdaptThunk<(), (), _>| {
+                        let _ = thunk.call(&mut ());
+                        MessageResult::RequestRebuild
+                    },
+                    button("button", |_: &mut ()| ()),
+                )
+            })
+        };
+
+        let prev = view();
+        let (mut id, mut state, mut element) = View::<(), ()>::build(&prev, &mut cx);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let result = View::<(), ()>::message(&prev, &[], &mut state, Box::new(()), &mut ());
+        assert!(matches!(result, MessageResult::RequestRebuild));
+
+        let next = view();
+        let _ = View::<(), ()>::rebuild(&next, &mut cx, &prev, &mut id, &mut state, &mut element);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let last = view();
+        let _ = View::<(), ()>::rebuild(&last, &mut cx, &next, &mut id, &mut state, &mut element);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -12,22 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-// mod adapt;
+mod adapt;
 // mod async_list;
 mod button;
 // mod layout_observer;
 // mod list;
-// mod memoize;
 // mod scroll_view;
 // mod text;
 // mod use_state;
 mod linear_layout;
 mod list;
+mod memoize;
 mod view;
 
-pub use xilem_core::{Id, IdPath, VecSplice};
+pub use xilem_core::{fragment, Id, IdPath, VecSplice};
 
+pub use adapt::{adapt, map_state, Adapt, AdaptThunk};
 pub use button::button;
 pub use linear_layout::{h_stack, v_stack, LinearLayout};
 pub use list::{list, List};
+pub use memoize::{memoize, Memoize};
 pub use view::{Cx, View, ViewMarker, ViewSequence};
@@ -108,3 +108,101 @@ impl Cx {
         self.pending_async.insert(id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+    use std::sync::mpsc::sync_channel;
+
+    use super::*;
+    use crate::{
+        view::{button, fragment},
+        MessageResult,
+    };
+    use test_log::test;
+
+    #[test]
+    fn sixteen_tuple_is_view_sequence() {
+        let (tx, _rx) = sync_channel(1);
+        let mut cx = Cx::new(&tx);
+        let b = || button("button", |_: &mut ()| ());
+        let seq = (
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+        );
+        let mut elements = vec![];
+        let state = ViewSequence::<()>::build(&seq, &mut cx, &mut elements);
+        assert_eq!(elements.len(), 16);
+        assert_eq!(ViewSequence::<()>::count(&seq, &state), 16);
+    }
+
+    #[test]
+    fn fragment_nests_long_sequences() {
+        let (tx, _rx) = sync_channel(1);
+        let mut cx = Cx::new(&tx);
+        let b = || button("button", |_: &mut ()| ());
+        let seq = fragment![
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+            b(),
+        ];
+        let mut elements = vec![];
+        let state = ViewSequence::<()>::build(&seq, &mut cx, &mut elements);
+        assert_eq!(elements.len(), 20);
+        assert_eq!(ViewSequence::<()>::count(&seq, &state), 20);
+    }
+
+    fn send_to_mismatched_state() -> MessageResult<()> {
+        let view: Box<dyn AnyView<()> + Send> = Box::new(button("button", |_: &mut ()| ()));
+        // The button's state is `()`, so this violates the view/state invariant.
+        let mut state: Box<dyn Any + Send> = Box::new(0u32);
+        view.message(&[], &mut state, Box::new(()), &mut ())
+    }
+
+    #[test]
+    #[cfg(not(feature = "panic-on-state-mismatch"))]
+    fn any_view_message_with_mismatched_state_is_stale() {
+        assert!(matches!(
+            send_to_mismatched_state(),
+            MessageResult::Stale(_)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "panic-on-state-mismatch")]
+    #[should_panic(expected = "downcast of state failed in dyn_message")]
+    fn any_view_message_with_mismatched_state_panics() {
+        let _ = send_to_mismatched_state();
+    }
+}